@@ -0,0 +1,38 @@
+use std::io::{self, BufRead, Write};
+
+use rkaley::diagnostics::Diagnostic;
+use rkaley::lexer::Lexer;
+
+// NOTE: this is a deliberately scaled-down stand-in for the REPL the
+// originating request actually asked for (parse each line via
+// `Parser::from_source`/`parse_definition`, print the AST, and buffer lines
+// into a session so a multi-line `create ... in ...` block is only
+// evaluated once it parses as a complete definition). There's no `Parser`
+// anywhere in this tree, so none of that is implementable yet. Until it
+// lands, this just lexes each line on its own and prints its tokens
+// (together with the span they came from), or a rendered diagnostic if the
+// line doesn't lex -- useful for exploring the lexer, but not the feature
+// that was requested.
+fn main() {
+    let stdin = io::stdin();
+
+    loop {
+        print!("kaleidoscope> ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            break; // EOF
+        }
+
+        for result in Lexer::new(&line) {
+            match result {
+                Ok((tok, span)) => println!("{:?} {:?}", tok, span),
+                Err(err) => {
+                    eprintln!("{}", Diagnostic::from(err).render(&line));
+                    break;
+                }
+            }
+        }
+    }
+}
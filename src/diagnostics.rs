@@ -0,0 +1,103 @@
+use crate::lexer::{LexError, Lexer, Span};
+
+// A human-readable report about a single problem in some `source` text,
+// ready to be printed to a terminal.
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Diagnostic {
+    pub fn new(message: impl Into<String>, span: Span) -> Diagnostic {
+        Diagnostic { message: message.into(), span }
+    }
+
+    // Renders a `line:col: error: message` header, then the line the
+    // diagnostic's span starts on, then a caret line underlining the span,
+    // e.g.:
+    //
+    //   2:16: error: invalid number literal `1.2.3`
+    //   game's width = 1.2.3
+    //                  ^^^^^
+    pub fn render(&self, source: &str) -> String {
+        let (line, col) = Lexer::offset_to_line_col(source, self.span.start);
+
+        let line_start = source[..self.span.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[self.span.start..]
+            .find('\n')
+            .map_or(source.len(), |i| self.span.start + i);
+        let line_text = &source[line_start..line_end];
+
+        // These widths feed `.repeat()` below, which counts chars, not bytes
+        // -- so they have to be char counts too, or the caret drifts on any
+        // multibyte char before or inside the span (mirrors
+        // `offset_to_line_col`, which counts chars for the same reason).
+        let caret_offset = source[line_start..self.span.start].chars().count();
+        let caret_len = source[self.span.start..self.span.end].chars().count().max(1);
+
+        format!(
+            "{}:{}: error: {}\n{}\n{}{}",
+            line,
+            col,
+            self.message,
+            line_text,
+            " ".repeat(caret_offset),
+            "^".repeat(caret_len),
+        )
+    }
+}
+
+impl From<LexError> for Diagnostic {
+    fn from(err: LexError) -> Diagnostic {
+        match err {
+            LexError::InvalidNumber { text, span } => {
+                Diagnostic::new(format!("invalid number literal `{}`", text), span)
+            }
+            LexError::UnterminatedString { span } => {
+                Diagnostic::new("unterminated string literal", span)
+            }
+            LexError::UnexpectedChar { found, span } => {
+                Diagnostic::new(format!("unexpected character `{}`", found), span)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_points_at_the_span() {
+        let source = "create game\ngame's width = 1.2.3";
+        let span = Span { start: 27, end: 32 };
+        let diagnostic = Diagnostic::new("invalid number literal `1.2.3`", span);
+        let report = diagnostic.render(source);
+        assert_eq!(
+            report,
+            "2:16: error: invalid number literal `1.2.3`\ngame's width = 1.2.3\n               ^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_points_at_the_span_with_multibyte_chars_before_it() {
+        let source = "café 1.2.3";
+        // "café " is 6 bytes (é is 2 bytes) but only 5 chars; the caret must
+        // be padded by 5 spaces (chars), not 6 (bytes), so it lands under `1`.
+        let span = Span { start: 6, end: 11 };
+        let diagnostic = Diagnostic::new("invalid number literal `1.2.3`", span);
+        let report = diagnostic.render(source);
+        assert_eq!(
+            report,
+            "1:6: error: invalid number literal `1.2.3`\ncafé 1.2.3\n     ^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_lex_error_converts_to_diagnostic() {
+        let err = LexError::UnterminatedString { span: Span { start: 0, end: 6 } };
+        let diagnostic: Diagnostic = err.into();
+        assert_eq!(diagnostic.message, "unterminated string literal");
+        assert_eq!(diagnostic.span, Span { start: 0, end: 6 });
+    }
+}
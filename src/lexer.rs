@@ -2,7 +2,7 @@ use std::iter::Peekable;
 use std::str::Chars;
 
 #[derive(Debug, PartialEq)]
-pub enum Token {
+pub enum Token<'a> {
     // Commands
     Define,
     Having,
@@ -10,129 +10,375 @@ pub enum Token {
     Extern,
     Block,
     EndLine,
-    // Identifier contains the identifier as a String.
-    Identifier(String),
+    // Identifier borrows the identifier's text straight out of `source`.
+    Identifier(&'a str),
     // Simple version, all num in Kaleidoscope are 64 bit floats
     // We store number in the variant instead of in a global variable.
     Number(f64),
-    String(String),
-    // UnknownChar corresponds to returning a positive integer from gettok.
+    // String borrows the text between the quotes straight out of `source`.
+    String(&'a str),
+    // Arithmetic operators.
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    // Brackets and separators.
+    LeftParen,
+    RightParen,
+    Comma,
+    // Comparison operators.
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    EqualEqual,
+    NotEqual,
+    // UnknownChar is the genuine fallback for bytes that match no other token.
     UnknownChar(char),
 }
 
+// Span marks the half-open byte range `[start, end)` a token occupied in the
+// original source string, so callers can report errors without re-lexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+// Everything that can go wrong while turning source text into tokens. Each
+// variant carries the `Span` of the offending text so callers can point at it.
+#[derive(Debug, PartialEq)]
+pub enum LexError {
+    InvalidNumber { text: String, span: Span },
+    UnterminatedString { span: Span },
+    UnexpectedChar { found: char, span: Span },
+}
+
+// The shape of a token class, matched against `source` starting at a byte
+// offset. `get_token` tries every pattern in `TOKEN_PATTERNS` at the current
+// position and takes the longest match ("maximal munch"), so adding a new
+// keyword or operator is a matter of adding a table row instead of another
+// `if`/`else` branch.
+#[derive(Clone, Copy)]
+enum Pattern {
+    // A fixed string, e.g. a keyword or an operator.
+    Literal(&'static str),
+    // One or more chars matching a single class, e.g. a run of digits.
+    While(fn(char) -> bool),
+    // Like `While`, but the first char is checked separately from the rest --
+    // covers shapes like an identifier's `[alpha][alnum]*`.
+    WhileFrom(fn(char) -> bool, fn(char) -> bool),
+    // Everything from `open` up to and including the next `close`. If `close`
+    // never shows up, the match runs to the end of `source` so the caller can
+    // tell an unterminated literal from "this pattern didn't apply at all".
+    Delimited { open: char, close: char },
+}
+
+impl Pattern {
+    // Returns the end offset of the longest match starting at `start`, if
+    // this pattern applies there at all.
+    fn matches(&self, source: &str, start: usize) -> Option<usize> {
+        match *self {
+            Pattern::Literal(text) => {
+                if source[start..].starts_with(text) {
+                    Some(start + text.len())
+                } else {
+                    None
+                }
+            }
+            Pattern::While(pred) => {
+                let mut end = start;
+                for c in source[start..].chars() {
+                    if !pred(c) {
+                        break;
+                    }
+                    end += c.len_utf8();
+                }
+                if end > start { Some(end) } else { None }
+            }
+            Pattern::WhileFrom(first, rest) => {
+                let mut chars = source[start..].chars();
+                let first_char = match chars.next() {
+                    Some(c) if first(c) => c,
+                    _ => return None,
+                };
+                let mut end = start + first_char.len_utf8();
+                for c in chars {
+                    if !rest(c) {
+                        break;
+                    }
+                    end += c.len_utf8();
+                }
+                Some(end)
+            }
+            Pattern::Delimited { open, close } => {
+                let rest = &source[start..];
+                let mut chars = rest.chars();
+                match chars.next() {
+                    Some(c) if c == open => {}
+                    _ => return None,
+                }
+                let after_open = start + open.len_utf8();
+                match source[after_open..].find(close) {
+                    Some(rel) => Some(after_open + rel + close.len_utf8()),
+                    // Unterminated: consume to the end of input so the
+                    // builder can tell this apart from "no match at all".
+                    None => Some(source.len()),
+                }
+            }
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic()
+}
+
+fn is_identifier_continue(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+fn is_number_char(c: char) -> bool {
+    c.is_ascii_digit() || c == '.'
+}
+
+// A builder turns the matched lexeme (and its span, for errors) into a
+// token. Most builders ignore their argument; `build_number` and
+// `build_string` validate it.
+type TokenBuilder = for<'s> fn(&'s str, Span) -> Result<Token<'s>, LexError>;
+
+fn build_define<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Define) }
+fn build_extern<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Extern) }
+fn build_block<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Block) }
+fn build_identifier<'a>(text: &'a str, _: Span) -> Result<Token<'a>, LexError> {
+    Ok(Token::Identifier(text))
+}
+fn build_number<'a>(text: &'a str, span: Span) -> Result<Token<'a>, LexError> {
+    text.parse()
+        .map(Token::Number)
+        .map_err(|_| LexError::InvalidNumber { text: text.to_string(), span })
+}
+fn build_string<'a>(text: &'a str, span: Span) -> Result<Token<'a>, LexError> {
+    // `text` spans from the opening `"` to (ideally) the closing `"`.
+    if text.len() >= 2 && text.ends_with('"') {
+        Ok(Token::String(&text[1..text.len() - 1]))
+    } else {
+        Err(LexError::UnterminatedString { span })
+    }
+}
+fn build_plus<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Plus) }
+fn build_minus<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Minus) }
+fn build_star<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Star) }
+fn build_slash<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Slash) }
+fn build_left_paren<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::LeftParen) }
+fn build_right_paren<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::RightParen) }
+fn build_comma<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Comma) }
+fn build_less<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Less) }
+fn build_less_equal<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::LessEqual) }
+fn build_greater<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Greater) }
+fn build_greater_equal<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::GreaterEqual) }
+fn build_equal_equal<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::EqualEqual) }
+fn build_not_equal<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::NotEqual) }
+fn build_assigned<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::Assigned) }
+fn build_end_line<'a>(_: &'a str, _: Span) -> Result<Token<'a>, LexError> { Ok(Token::EndLine) }
+
+// Token classes, tried in order at the current position. The longest match
+// wins; ties are broken by table order, which is why each keyword is listed
+// ahead of the general identifier pattern it would otherwise tie with.
+const TOKEN_PATTERNS: &[(Pattern, TokenBuilder)] = &[
+    (Pattern::Literal("create"), build_define),
+    (Pattern::Literal("extern"), build_extern),
+    (Pattern::Literal("in"), build_block),
+    (Pattern::WhileFrom(is_identifier_start, is_identifier_continue), build_identifier),
+    (Pattern::While(is_number_char), build_number),
+    (Pattern::Delimited { open: '"', close: '"' }, build_string),
+    (Pattern::Literal("=="), build_equal_equal),
+    (Pattern::Literal("!="), build_not_equal),
+    (Pattern::Literal("<="), build_less_equal),
+    (Pattern::Literal(">="), build_greater_equal),
+    (Pattern::Literal("<"), build_less),
+    (Pattern::Literal(">"), build_greater),
+    (Pattern::Literal("="), build_assigned),
+    (Pattern::Literal("+"), build_plus),
+    (Pattern::Literal("-"), build_minus),
+    (Pattern::Literal("*"), build_star),
+    (Pattern::Literal("/"), build_slash),
+    (Pattern::Literal("("), build_left_paren),
+    (Pattern::Literal(")"), build_right_paren),
+    (Pattern::Literal(","), build_comma),
+    (Pattern::Literal("\n"), build_end_line),
+];
+
+// Tries every pattern at `start` and returns the longest match, preferring
+// the earlier table entry on a tie.
+fn longest_match(source: &str, start: usize) -> Option<(usize, TokenBuilder)> {
+    let mut best: Option<(usize, TokenBuilder)> = None;
+    for (pattern, builder) in TOKEN_PATTERNS {
+        if let Some(end) = pattern.matches(source, start) {
+            let is_longer = match best {
+                Some((best_end, _)) => end > best_end,
+                None => true,
+            };
+            if is_longer {
+                best = Some((end, *builder));
+            }
+        }
+    }
+    best
+}
+
 // Lexer is implemented as a struct that holds its state instead of a function that works on
 // global state
 #[derive(Debug)]
 pub struct Lexer<'a> {
     chars: Peekable<Chars<'a>>,
     source: &'a str,
+    // Byte offset of the next char to be pulled from `chars`.
+    pos: usize,
 }
 
 impl<'a> Iterator for Lexer<'a> {
-    // We allow iterating over Tokens
-    type Item = Token;
+    // We allow iterating over tokens together with the span they came from,
+    // failing fast on malformed input instead of panicking.
+    type Item = Result<(Token<'a>, Span), LexError>;
 
-    fn next(&mut self) -> Option<Token> {
+    fn next(&mut self) -> Option<Result<(Token<'a>, Span), LexError>> {
         // next is first char that not equal whitespace
-        let next = self.chars
-            .find(|&c| c != ' ');
+        let mut c = self.bump()?;
+        while c == ' ' {
+            c = self.bump()?;
+        }
 
-        match next {
-            Some(c) => self.get_token(c),
-            None => None,
+        let start = self.pos - c.len_utf8();
+        match self.get_token(c, start) {
+            Ok(Some((token, start))) => Some(Ok((token, Span { start, end: self.pos }))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
         }
     }
 }
 
 impl <'a> Lexer<'a> {
-    fn get_token(&mut self, c: char) -> Option<Token> {
-        if c.is_alphabetic() {
-            let mut iden = String::new();
-            iden.push(c);
-
-            loop {
-                // We create nested block so xp will be out of scope
-                // when self.chars.next() is called
-                {
-                    let xp = self.chars.peek();
-                    match xp {
-                        Some(c) if c.is_alphanumeric() => iden.push(*c),
-                        _ => break,
-                    }
-                };
-                self.chars.next();
-            }
+    // Pulls the next char out of `chars`, advancing `pos` by its UTF-8 width
+    // (not 1) so byte offsets stay correct for multibyte input.
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
 
-            match iden.as_str() {
-                "create" => Some(Token::Define),
-                "extern" => Some(Token::Extern),
-                "in" => Some(Token::Block),
-                _ => Some(Token::Identifier(iden)),
+    // Keeps pulling chars until `pos` reaches `end`, to catch `chars` up
+    // after computing a match directly against `source`.
+    fn advance_to(&mut self, end: usize) {
+        while self.pos < end {
+            if self.bump().is_none() {
+                break;
             }
-        } else if c == '\'' {
-            let xp = self.chars.next();
-            match xp {
-                Some(c) if c == 's' => Some(Token::Having),
-                _ => Some(Token::UnknownChar(c)),
-            }
-        } else if c.is_digit(10) || c == '.' {
-            let mut num = String::new();
-            num.push(c);
+        }
+    }
 
+    // `start` is the byte offset `c` was read from, so any error raised while
+    // lexing this token can report a span that starts where the token did.
+    // Returns the token together with its own start offset, which differs
+    // from `start` when a comment was skipped first.
+    //
+    // Comments and `'s` are deliberately NOT in `TOKEN_PATTERNS`: a comment
+    // isn't a token at all (it's skipped), and a bare `'` needs to eagerly
+    // raise `LexError::UnexpectedChar` rather than fall back to
+    // `UnknownChar` the way an unmatched pattern normally would. Everything
+    // else goes through the declarative table via `longest_match`.
+    fn get_token(&mut self, c: char, start: usize) -> Result<Option<(Token<'a>, usize)>, LexError> {
+        if c == '#' {
             loop {
                 // We create nested block so xp will be out of scope
-                // when self.chars.next() is called
+                // when self.bump() is called
                 {
                     let xp = self.chars.peek();
                     match xp {
-                        Some(c) if c.is_digit(10) || *c == '.' => num.push(*c),
+                        // just eat the chars
+                        Some(c) if *c != '\r' && *c != '\n' => {},
                         _ => break,
                     }
                 };
-                self.chars.next();
+                self.bump();
             }
-            Some(Token::Number(num.parse().expect("Can't parse number")))
-        } else if c == '"' {
-            let mut iden = String::new();
-            loop {
-                let xp = self.chars.peek();
-                match xp {
-                    Some(c) if *c != '"' => iden.push(*c),
-                    _ => break,
-                };
-                self.chars.next();
+            // The loop above stops right before the newline that ends the
+            // comment line (or at EOF); consume that newline too so it
+            // doesn't get re-dispatched into `get_token` as its own
+            // `EndLine` token -- a comment and the line it's on are one
+            // skippable unit, not a comment followed by a line break.
+            if self.chars.peek() == Some(&'\r') {
+                self.bump();
             }
-            // after loop, we next again to bypass the end '"'
-            self.chars.next();
-            Some(Token::String(iden))
-        } else if c == '=' {
-            Some(Token::Assigned)
-        } else if c == '\n' {
-            Some(Token::EndLine)
-        } else if c == '#' {
-            loop {
-                // We create nested block so xp will be out of scope
-                // when self.chars.next() is called
-                {
-                    let xp = self.chars.peek();
-                    match xp {
-                        // just eat the chars
-                        Some(c) if *c != '\r' && *c != '\n' => {},
-                        _ => break,
-                    }
+            if self.chars.peek() == Some(&'\n') {
+                self.bump();
+            }
+            // Keep skipping whitespace and re-enter get_token for the next
+            // real token, rather than recursing into `next` (which would
+            // also have to re-derive the span's start offset).
+            let mut c = match self.bump() {
+                Some(c) => c,
+                None => return Ok(None),
+            };
+            while c == ' ' {
+                c = match self.bump() {
+                    Some(c) => c,
+                    None => return Ok(None),
                 };
-                self.chars.next();
             }
-            self.next()
+            let start = self.pos - c.len_utf8();
+            self.get_token(c, start)
+        } else if c == '\'' {
+            // `'s` is the only thing this can lex to; anything else is a
+            // genuine error rather than a case for the `UnknownChar`
+            // fallback, so it's handled outside the pattern table.
+            match self.bump() {
+                Some('s') => Ok(Some((Token::Having, start))),
+                Some(found) => Err(LexError::UnexpectedChar {
+                    found,
+                    span: Span { start, end: self.pos },
+                }),
+                None => Err(LexError::UnexpectedChar {
+                    found: c,
+                    span: Span { start, end: self.pos },
+                }),
+            }
         } else {
-            Some(Token::UnknownChar(c))
+            match longest_match(self.source, start) {
+                Some((end, build)) => {
+                    self.advance_to(end);
+                    let text = &self.source[start..end];
+                    build(text, Span { start, end }).map(|token| Some((token, start)))
+                }
+                None => Ok(Some((Token::UnknownChar(c), start))),
+            }
+        }
+    }
+
+    // Turns a byte offset into `source` into a 1-based (line, col) pair, for
+    // reporting positions to humans.
+    pub fn offset_to_line_col(source: &str, offset: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut col = 1;
+        for (i, c) in source.char_indices() {
+            if i >= offset {
+                break;
+            }
+            if c == '\n' {
+                line += 1;
+                col = 1;
+            } else {
+                col += 1;
+            }
         }
+        (line, col)
     }
 
     pub fn new(source: &'a str) -> Lexer<'a> {
         Lexer {
             chars: source.chars().peekable(),
             source,
+            pos: 0,
         }
     }
 }
@@ -143,21 +389,21 @@ mod tests {
     #[test]
     fn test_simple_tokens_and_value() {
         let mut lexer = Lexer::new("1 + 1 - foo");
-        assert_eq!(lexer.next().unwrap(), Token::Number(1.0));
-        assert_eq!(lexer.next().unwrap(), Token::UnknownChar('+'));
-        assert_eq!(lexer.next().unwrap(), Token::Number(1.0));
-        assert_eq!(lexer.next().unwrap(), Token::UnknownChar('-'));
-        assert_eq!(lexer.next().unwrap(), Token::Identifier(String::from("foo")));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Plus);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Minus);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Identifier("foo"));
         assert_eq!(lexer.next(), None);
     }
     #[test]
     fn test_simple_tokens_and_value_no_whitespace() {
         let mut lexer = Lexer::new("1+1-foo");
-        assert_eq!(lexer.next().unwrap(), Token::Number(1.0));
-        assert_eq!(lexer.next().unwrap(), Token::UnknownChar('+'));
-        assert_eq!(lexer.next().unwrap(), Token::Number(1.0));
-        assert_eq!(lexer.next().unwrap(), Token::UnknownChar('-'));
-        assert_eq!(lexer.next().unwrap(), Token::Identifier(String::from("foo")));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Plus);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Minus);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Identifier("foo"));
         assert_eq!(lexer.next(), None);
     }
     #[test]
@@ -166,9 +412,107 @@ mod tests {
         1 + 2 # <- is code
         # this is not";
         let mut lexer = Lexer::new(code);
-        assert_eq!(lexer.next(), Some(Token::Number(1.0)));
-        assert_eq!(lexer.next(), Some(Token::UnknownChar('+')));
-        assert_eq!(lexer.next(), Some(Token::Number(2.0)));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Plus);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(2.0));
+        assert_eq!(lexer.next(), None);
+    }
+    #[test]
+    fn test_spans_cover_the_token_text() {
+        let source = "foo bar";
+        let mut lexer = Lexer::new(source);
+        let (token, span) = lexer.next().unwrap().unwrap();
+        assert_eq!(token, Token::Identifier("foo"));
+        assert_eq!(span, Span { start: 0, end: 3 });
+        assert_eq!(&source[span.start..span.end], "foo");
+
+        let (token, span) = lexer.next().unwrap().unwrap();
+        assert_eq!(token, Token::Identifier("bar"));
+        assert_eq!(span, Span { start: 4, end: 7 });
+    }
+    #[test]
+    fn test_line_col_tracks_newlines() {
+        let source = "create\nin";
+        assert_eq!(Lexer::offset_to_line_col(source, 0), (1, 1));
+        assert_eq!(Lexer::offset_to_line_col(source, 7), (2, 1));
+        assert_eq!(Lexer::offset_to_line_col(source, 8), (2, 2));
+    }
+    #[test]
+    fn test_multibyte_chars_land_on_correct_byte_offsets() {
+        // `é` is 2 bytes in UTF-8; spans and line/col must count bytes, not
+        // chars, while columns still count `é` as a single character.
+        let source = "café 1";
+        let mut lexer = Lexer::new(source);
+
+        let (token, span) = lexer.next().unwrap().unwrap();
+        assert_eq!(token, Token::Identifier("café"));
+        assert_eq!(span, Span { start: 0, end: 5 });
+        assert_eq!(&source[span.start..span.end], "café");
+        assert_eq!(Lexer::offset_to_line_col(source, span.end), (1, 5));
+
+        let (token, span) = lexer.next().unwrap().unwrap();
+        assert_eq!(token, Token::Number(1.0));
+        assert_eq!(span, Span { start: 6, end: 7 });
+
         assert_eq!(lexer.next(), None);
     }
-}
\ No newline at end of file
+    #[test]
+    fn test_unterminated_string_is_an_error() {
+        let mut lexer = Lexer::new("\"hello");
+        assert_eq!(
+            lexer.next(),
+            Some(Err(LexError::UnterminatedString { span: Span { start: 0, end: 6 } }))
+        );
+    }
+    #[test]
+    fn test_invalid_number_is_an_error() {
+        let mut lexer = Lexer::new("1.2.3");
+        assert_eq!(
+            lexer.next(),
+            Some(Err(LexError::InvalidNumber {
+                text: String::from("1.2.3"),
+                span: Span { start: 0, end: 5 },
+            }))
+        );
+    }
+    #[test]
+    fn test_operator_tokens() {
+        let mut lexer = Lexer::new("(1, 2) * 3 / 4");
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::LeftParen);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(1.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Comma);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(2.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::RightParen);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Star);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(3.0));
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Slash);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Number(4.0));
+        assert_eq!(lexer.next(), None);
+    }
+    #[test]
+    fn test_multi_char_comparison_tokens() {
+        let mut lexer = Lexer::new("< <= > >= == !=");
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Less);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::LessEqual);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Greater);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::GreaterEqual);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::EqualEqual);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::NotEqual);
+        assert_eq!(lexer.next(), None);
+    }
+    #[test]
+    fn test_keyword_wins_tie_against_identifier_pattern() {
+        let mut lexer = Lexer::new("in increment");
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Block);
+        assert_eq!(lexer.next().unwrap().unwrap().0, Token::Identifier("increment"));
+        assert_eq!(lexer.next(), None);
+    }
+    #[test]
+    fn test_apostrophe_without_s_is_an_error() {
+        let mut lexer = Lexer::new("'x");
+        assert_eq!(
+            lexer.next(),
+            Some(Err(LexError::UnexpectedChar { found: 'x', span: Span { start: 0, end: 2 } }))
+        );
+    }
+}
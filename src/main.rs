@@ -1,6 +1,10 @@
+use rkaley::diagnostics::Diagnostic;
 use rkaley::lexer::Lexer;
-use rkaley::parser::Parser;
 
+// There's no parser in this tree yet, so this just runs the sample through
+// the lexer and prints its tokens (or a rendered diagnostic on the first
+// lex error). Swap this over to `Parser::from_source(...).parse_definition()`
+// once a `parser` module lands.
 fn main() {
     let sample = r#"
     # print a hello world
@@ -10,9 +14,14 @@ fn main() {
     in game
       print "hello world!"
     "#;
-    // let lex = Lexer::new(sample);
-    // lex.for_each(|tok| println!("tok: {:?}", tok));
-    let par = &mut Parser::from_source(sample);
-    let ast = &mut par.parse_definition().unwrap();
-    println!("ast: {:?}", ast);
+    for result in Lexer::new(sample) {
+        match result {
+            Ok((tok, _span)) => println!("tok: {:?}", tok),
+            Err(err) => {
+                let diagnostic: Diagnostic = err.into();
+                eprintln!("{}", diagnostic.render(sample));
+                return;
+            }
+        }
+    }
 }